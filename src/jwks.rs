@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    err,
+    error::{Error, Type},
+    jwk::Jwk,
+};
+
+/// A JWK Set as described in [RFC7517 §5](https://www.rfc-editor.org/rfc/rfc7517#section-5),
+/// the document most OIDC providers publish their current signing keys as.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JwkSet {
+    pub keys: Vec<Jwk>,
+}
+
+impl JwkSet {
+    pub fn parse(document: &str) -> Result<Self, Error> {
+        serde_json::from_str(document).or(Err(err!(Invalid, "Failed to decode key set")))
+    }
+
+    /// Returns the key in this set whose `kid` matches, if any.
+    pub fn find(&self, kid: &str) -> Option<&Jwk> {
+        self.keys.iter().find(|key| key.kid.as_deref() == Some(kid))
+    }
+
+    /// Downloads a provider's JWKS document, e.g. from its
+    /// `jwks_uri` as published in `/.well-known/openid-configuration`.
+    #[cfg(feature = "reqwest")]
+    pub fn fetch(url: &str) -> Result<Self, Error> {
+        let body = reqwest::blocking::get(url)
+            .or(Err(err!(Connection, "Could not download key set")))?
+            .text()
+            .or(Err(err!(Connection, "Could not download key set")))?;
+        Self::parse(&body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_by_kid() {
+        let set = JwkSet::parse(&format!(
+            r#"{{"keys":[{}]}}"#,
+            include_str!("rs256_2048_private_key.json")
+        ))
+        .unwrap();
+
+        assert!(set.find("test").is_some());
+        assert!(set.find("missing").is_none());
+    }
+}
@@ -0,0 +1,7 @@
+//! A small, dependency-light implementation of JSON Web Tokens and JSON Web
+//! Keys.
+
+pub mod error;
+pub mod jwk;
+pub mod jwks;
+pub mod jwt;